@@ -2,8 +2,8 @@ use chrono::{TimeZone, Utc};
 use range_time::{TimeRangeBuilder, TimeStep};
 
 fn main() {
-    let start = Utc.ymd(2024, 1, 1).and_hms(0, 0, 0);
-    let end = Utc.ymd(2024, 1, 2).and_hms(0, 0, 0);
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
 
     let range = TimeRangeBuilder::new()
         .start(start)
@@ -0,0 +1,478 @@
+//! A small cron-style schedule matcher, in the spirit of the classic 5/6
+//! field cron expression (`sec min hour day-of-month month day-of-week`,
+//! with `sec` optional), supporting `*`, lists (`a,b`), ranges (`a-b`) and
+//! steps (`*/n`, `a-b/n`) in each field.
+use crate::days_in_month;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// An error produced while parsing a [`CronSchedule`] expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronParseError(String);
+
+impl fmt::Display for CronParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for CronParseError {}
+
+/// A parsed cron expression that can be walked forward in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    seconds: Vec<u32>,
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    days_of_month: Vec<u32>,
+    months: Vec<u32>,
+    days_of_week: Vec<u32>,
+    dom_is_wildcard: bool,
+    dow_is_wildcard: bool,
+}
+
+impl CronSchedule {
+    /// Parse a classic 5-field (`min hour dom month dow`) or 6-field (`sec
+    /// min hour dom month dow`) cron expression.
+    pub fn parse(expr: &str) -> Result<CronSchedule, CronParseError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+
+        let (sec_f, min_f, hour_f, dom_f, month_f, dow_f) = match fields.as_slice() {
+            [min, hour, dom, month, dow] => ("0", *min, *hour, *dom, *month, *dow),
+            [sec, min, hour, dom, month, dow] => (*sec, *min, *hour, *dom, *month, *dow),
+            _ => {
+                return Err(CronParseError(format!(
+                    "expected 5 or 6 fields, got {}",
+                    fields.len()
+                )))
+            }
+        };
+
+        let (seconds, _) = parse_field(sec_f, 0, 59)?;
+        let (minutes, _) = parse_field(min_f, 0, 59)?;
+        let (hours, _) = parse_field(hour_f, 0, 23)?;
+        let (days_of_month, dom_is_wildcard) = parse_field(dom_f, 1, 31)?;
+        let (months, _) = parse_field(month_f, 1, 12)?;
+        let (days_of_week, dow_is_wildcard) = parse_field(dow_f, 0, 6)?;
+
+        // When day-of-week is a wildcard, `day_matches` falls back to
+        // day-of-month alone (see its doc comment), so at least one configured
+        // day-of-month must actually occur in at least one configured
+        // month, or `next_match`/`prev_match` would roll months forever
+        // looking for a day that never comes (e.g. `31 2 *`: day 31 never
+        // falls in February).
+        if !dom_is_wildcard && dow_is_wildcard {
+            let reachable = months
+                .iter()
+                .any(|&m| days_of_month.iter().any(|&d| d <= max_days_in_month(m)));
+            if !reachable {
+                return Err(CronParseError(format!(
+                    "day-of-month {:?} is never reachable in month(s) {:?}",
+                    days_of_month, months
+                )));
+            }
+        }
+
+        Ok(CronSchedule {
+            seconds,
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            dom_is_wildcard,
+            dow_is_wildcard,
+        })
+    }
+
+    /// Yield each instant matching this schedule from `start` onward
+    /// (inclusive).
+    pub fn iter_from(&self, start: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        CronScheduleIter {
+            schedule: self,
+            current: Some(start),
+        }
+    }
+
+    /// The smallest matching instant that is `>= from`.
+    pub(crate) fn next_match(&self, from: DateTime<Utc>) -> DateTime<Utc> {
+        let mut dt = from;
+        loop {
+            let naive = dt.naive_utc();
+
+            if !self.months.contains(&naive.month()) {
+                dt = self.roll_month(dt);
+                continue;
+            }
+
+            if !self.day_matches(naive.date()) {
+                dt = roll_day(dt);
+                continue;
+            }
+
+            match ceil(&self.hours, naive.hour()) {
+                Some(h) if h == naive.hour() => {}
+                Some(h) => {
+                    dt = set_hour(dt, h);
+                    continue;
+                }
+                None => {
+                    dt = roll_day(dt);
+                    continue;
+                }
+            }
+
+            match ceil(&self.minutes, naive.minute()) {
+                Some(m) if m == naive.minute() => {}
+                Some(m) => {
+                    dt = set_minute(dt, m);
+                    continue;
+                }
+                None => {
+                    dt = roll_hour(dt);
+                    continue;
+                }
+            }
+
+            match ceil(&self.seconds, naive.second()) {
+                Some(s) if s == naive.second() => {}
+                Some(s) => {
+                    dt = set_second(dt, s);
+                    continue;
+                }
+                None => {
+                    dt = roll_minute(dt);
+                    continue;
+                }
+            }
+
+            return dt;
+        }
+    }
+
+    /// The largest matching instant that is `<= from`. The mirror image of
+    /// [`next_match`](CronSchedule::next_match), used to walk a schedule
+    /// backward (e.g. for [`DoubleEndedIterator`](std::iter::DoubleEndedIterator)
+    /// support on a scheduled `TimeRange`).
+    pub(crate) fn prev_match(&self, from: DateTime<Utc>) -> DateTime<Utc> {
+        let mut dt = from;
+        loop {
+            let naive = dt.naive_utc();
+
+            if !self.months.contains(&naive.month()) {
+                dt = self.roll_month_back(dt);
+                continue;
+            }
+
+            if !self.day_matches(naive.date()) {
+                dt = roll_day_back(dt);
+                continue;
+            }
+
+            match floor(&self.hours, naive.hour()) {
+                Some(h) if h == naive.hour() => {}
+                Some(h) => {
+                    dt = set_hour_back(dt, h);
+                    continue;
+                }
+                None => {
+                    dt = roll_day_back(dt);
+                    continue;
+                }
+            }
+
+            match floor(&self.minutes, naive.minute()) {
+                Some(m) if m == naive.minute() => {}
+                Some(m) => {
+                    dt = set_minute_back(dt, m);
+                    continue;
+                }
+                None => {
+                    dt = roll_hour_back(dt);
+                    continue;
+                }
+            }
+
+            match floor(&self.seconds, naive.second()) {
+                Some(s) if s == naive.second() => {}
+                Some(s) => {
+                    dt = set_second_back(dt, s);
+                    continue;
+                }
+                None => {
+                    dt = roll_minute_back(dt);
+                    continue;
+                }
+            }
+
+            return dt;
+        }
+    }
+
+    /// Day-of-month and day-of-week are combined the way cron itself does:
+    /// if both are restricted (not `*`), a day matches if *either* field
+    /// allows it; if only one is restricted, that one alone decides.
+    fn day_matches(&self, date: NaiveDate) -> bool {
+        let dom_ok = self.days_of_month.contains(&date.day());
+        let dow_ok = self
+            .days_of_week
+            .contains(&date.weekday().num_days_from_sunday());
+
+        match (self.dom_is_wildcard, self.dow_is_wildcard) {
+            (true, true) => true,
+            (true, false) => dow_ok,
+            (false, true) => dom_ok,
+            (false, false) => dom_ok || dow_ok,
+        }
+    }
+
+    fn roll_month(&self, dt: DateTime<Utc>) -> DateTime<Utc> {
+        let naive = dt.naive_utc();
+        if let Some(month) = self.months.iter().copied().find(|&m| m > naive.month()) {
+            Utc.from_utc_datetime(
+                &NaiveDate::from_ymd_opt(naive.year(), month, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            )
+        } else {
+            let year = naive.year() + 1;
+            let month = self.months[0];
+            Utc.from_utc_datetime(
+                &NaiveDate::from_ymd_opt(year, month, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            )
+        }
+    }
+
+    fn roll_month_back(&self, dt: DateTime<Utc>) -> DateTime<Utc> {
+        let naive = dt.naive_utc();
+        if let Some(month) = self.months.iter().rev().copied().find(|&m| m < naive.month()) {
+            let year = naive.year();
+            let day = days_in_month(year, month);
+            Utc.from_utc_datetime(
+                &NaiveDate::from_ymd_opt(year, month, day)
+                    .unwrap()
+                    .and_hms_opt(23, 59, 59)
+                    .unwrap(),
+            )
+        } else {
+            let year = naive.year() - 1;
+            let month = *self.months.last().expect("cron fields are never empty");
+            let day = days_in_month(year, month);
+            Utc.from_utc_datetime(
+                &NaiveDate::from_ymd_opt(year, month, day)
+                    .unwrap()
+                    .and_hms_opt(23, 59, 59)
+                    .unwrap(),
+            )
+        }
+    }
+}
+
+/// The most days `month` can ever have, across any year (February is
+/// credited with 29 to account for leap years).
+fn max_days_in_month(month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => 29,
+        _ => 0,
+    }
+}
+
+fn ceil(allowed: &[u32], current: u32) -> Option<u32> {
+    allowed.iter().copied().find(|&v| v >= current)
+}
+
+fn roll_day(dt: DateTime<Utc>) -> DateTime<Utc> {
+    let next_date = dt.naive_utc().date() + Duration::days(1);
+    Utc.from_utc_datetime(&next_date.and_hms_opt(0, 0, 0).unwrap())
+}
+
+fn set_hour(dt: DateTime<Utc>, hour: u32) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    Utc.from_utc_datetime(&naive.date().and_hms_opt(hour, 0, 0).unwrap())
+}
+
+fn set_minute(dt: DateTime<Utc>, minute: u32) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    Utc.from_utc_datetime(&naive.date().and_hms_opt(naive.hour(), minute, 0).unwrap())
+}
+
+fn set_second(dt: DateTime<Utc>, second: u32) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    Utc.from_utc_datetime(
+        &naive
+            .date()
+            .and_hms_opt(naive.hour(), naive.minute(), second)
+            .unwrap(),
+    )
+}
+
+fn roll_hour(dt: DateTime<Utc>) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    let base = Utc.from_utc_datetime(&naive.date().and_hms_opt(naive.hour(), 0, 0).unwrap());
+    base + Duration::hours(1)
+}
+
+fn roll_minute(dt: DateTime<Utc>) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    let base = Utc.from_utc_datetime(
+        &naive
+            .date()
+            .and_hms_opt(naive.hour(), naive.minute(), 0)
+            .unwrap(),
+    );
+    base + Duration::minutes(1)
+}
+
+fn floor(allowed: &[u32], current: u32) -> Option<u32> {
+    allowed.iter().rev().copied().find(|&v| v <= current)
+}
+
+fn roll_day_back(dt: DateTime<Utc>) -> DateTime<Utc> {
+    let prev_date = dt.naive_utc().date() - Duration::days(1);
+    Utc.from_utc_datetime(&prev_date.and_hms_opt(23, 59, 59).unwrap())
+}
+
+fn set_hour_back(dt: DateTime<Utc>, hour: u32) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    Utc.from_utc_datetime(&naive.date().and_hms_opt(hour, 59, 59).unwrap())
+}
+
+fn set_minute_back(dt: DateTime<Utc>, minute: u32) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    Utc.from_utc_datetime(&naive.date().and_hms_opt(naive.hour(), minute, 59).unwrap())
+}
+
+fn set_second_back(dt: DateTime<Utc>, second: u32) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    Utc.from_utc_datetime(
+        &naive
+            .date()
+            .and_hms_opt(naive.hour(), naive.minute(), second)
+            .unwrap(),
+    )
+}
+
+fn roll_hour_back(dt: DateTime<Utc>) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    let base = Utc.from_utc_datetime(&naive.date().and_hms_opt(naive.hour(), 0, 0).unwrap());
+    base - Duration::seconds(1)
+}
+
+fn roll_minute_back(dt: DateTime<Utc>) -> DateTime<Utc> {
+    let naive = dt.naive_utc();
+    let base = Utc.from_utc_datetime(
+        &naive
+            .date()
+            .and_hms_opt(naive.hour(), naive.minute(), 0)
+            .unwrap(),
+    );
+    base - Duration::seconds(1)
+}
+
+/// Parse one cron field (a comma-separated list of `*`, `*/n`, `a`, `a-b` or
+/// `a-b/n` items) into its sorted, deduplicated set of allowed values, along
+/// with whether the field was a bare `*`.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<(Vec<u32>, bool), CronParseError> {
+    let is_wildcard = field == "*";
+    let mut values = BTreeSet::new();
+
+    for part in field.split(',') {
+        let (range_part, step) = match part.split_once('/') {
+            Some((range_part, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| CronParseError(format!("invalid step in `{}`", part)))?;
+                (range_part, step)
+            }
+            None => (part, 1),
+        };
+
+        if step == 0 {
+            return Err(CronParseError(format!("step cannot be zero in `{}`", part)));
+        }
+
+        let (start, end) = if range_part == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range_part.split_once('-') {
+            let a: u32 = a
+                .parse()
+                .map_err(|_| CronParseError(format!("invalid range in `{}`", part)))?;
+            let b: u32 = b
+                .parse()
+                .map_err(|_| CronParseError(format!("invalid range in `{}`", part)))?;
+            (a, b)
+        } else {
+            let v: u32 = range_part
+                .parse()
+                .map_err(|_| CronParseError(format!("invalid value `{}`", part)))?;
+            (v, v)
+        };
+
+        if start > end || start < min || end > max {
+            return Err(CronParseError(format!(
+                "`{}` is out of range {}..={}",
+                part, min, max
+            )));
+        }
+
+        let mut v = start;
+        while v <= end {
+            values.insert(v);
+            v += step;
+        }
+    }
+
+    if values.is_empty() {
+        return Err(CronParseError(format!("`{}` matches no values", field)));
+    }
+
+    Ok((values.into_iter().collect(), is_wildcard))
+}
+
+struct CronScheduleIter<'a> {
+    schedule: &'a CronSchedule,
+    current: Option<DateTime<Utc>>,
+}
+
+impl<'a> Iterator for CronScheduleIter<'a> {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.take()?;
+        let matched = self.schedule.next_match(current);
+        self.current = Some(matched + Duration::seconds(1));
+        Some(matched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreachable_day_of_month_is_rejected() {
+        // Day 31 can never fall in February.
+        assert!(CronSchedule::parse("0 0 31 2 *").is_err());
+    }
+
+    #[test]
+    fn reachable_day_of_month_across_months_is_accepted() {
+        // Day 31 is unreachable in April, but July (month 7) has a 31st,
+        // so the expression as a whole is satisfiable.
+        assert!(CronSchedule::parse("0 0 31 4,7 *").is_ok());
+    }
+
+    #[test]
+    fn restricted_day_of_week_is_unaffected_by_reachability_check() {
+        // dow is restricted (not `*`), so day_matches ORs in dow_ok and
+        // this is always satisfiable regardless of day-of-month/month.
+        assert!(CronSchedule::parse("0 0 31 2 1").is_ok());
+    }
+}
@@ -1,6 +1,14 @@
-use chrono::{DateTime, Datelike, Duration, Utc};
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Timelike, Utc, Weekday,
+};
 use std::fmt;
 
+mod cron;
+mod parser;
+pub use cron::{CronParseError, CronSchedule};
+pub use parser::ParseError;
+
 /// Time iteration.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TimeStep {
@@ -12,6 +20,15 @@ pub enum TimeStep {
     Hour(i64),
     /// Step by a given number of days
     Day(i64),
+    /// Step by a given number of weeks
+    Week(i64),
+    /// Step by a given number of calendar months, clamping the day of month
+    /// to the last valid day of the target month (e.g. Jan 31 + 1 month ->
+    /// Feb 28/29).
+    Month(i64),
+    /// Step by a given number of calendar years, clamping Feb 29 -> Feb 28
+    /// when the target year is not a leap year.
+    Year(i64),
 }
 impl From<TimeStep> for Duration {
     fn from(value: TimeStep) -> Self {
@@ -20,13 +37,22 @@ impl From<TimeStep> for Duration {
             TimeStep::Minute(m) => Duration::minutes(*m),
             TimeStep::Hour(h) => Duration::hours(*h),
             TimeStep::Day(d) => Duration::days(*d),
+            TimeStep::Week(w) => Duration::weeks(*w),
+            // Nominal durations: months and years don't have a fixed length,
+            // so these are only approximate. Actual iteration uses calendar
+            // arithmetic (see `add_step`), not this conversion.
+            TimeStep::Month(m) => Duration::days(*m * 30),
+            TimeStep::Year(y) => Duration::days(*y * 365),
         }
     }
 }
 impl TimeStep {
     /// Returns the total step size in seconds.
+    ///
+    /// For `Month` and `Year` this is a nominal value (30-day months,
+    /// 365-day years); the iterator itself uses correct calendar arithmetic.
     pub fn as_total_seconds(&self) -> i64 {
-        Duration::from(self.clone()).num_seconds()
+        Duration::from(*self).num_seconds()
     }
 }
 
@@ -37,137 +63,781 @@ impl fmt::Display for TimeStep {
             TimeStep::Minute(m) => write!(f, "{} minute(s)", m),
             TimeStep::Hour(h) => write!(f, "{} hour(s)", h),
             TimeStep::Day(d) => write!(f, "{} day(s)", d),
+            TimeStep::Week(w) => write!(f, "{} week(s)", w),
+            TimeStep::Month(m) => write!(f, "{} month(s)", m),
+            TimeStep::Year(y) => write!(f, "{} year(s)", y),
+        }
+    }
+}
+
+/// Advance `dt` by one `step`. `Second`/`Minute`/`Hour` add a fixed
+/// `Duration`; `Day`/`Week`/`Month`/`Year` use calendar arithmetic, so a
+/// "daily at 09:00 local" walk in a non-UTC zone steps by calendar
+/// days/months/years, not by a fixed offset that would drift across a DST
+/// transition. `Month`/`Year` are clamped against
+/// `anchor`'s day-of-month (not `dt`'s), so day-of-month clamping never
+/// compounds across repeated steps: stepping `anchor=Jan 31` by one month
+/// twice yields `Feb 29, Mar 31`, recovering to the 31st, rather than
+/// getting stuck at `Feb 29, Mar 29`.
+fn add_step<Tz: TimeZone>(dt: DateTime<Tz>, step: TimeStep, anchor: DateTime<Tz>) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    match step {
+        TimeStep::Second(s) => dt + Duration::seconds(s),
+        TimeStep::Minute(m) => dt + Duration::minutes(m),
+        TimeStep::Hour(h) => dt + Duration::hours(h),
+        TimeStep::Day(d) => add_calendar_days(dt, d),
+        TimeStep::Week(w) => add_calendar_days(dt, w * 7),
+        TimeStep::Month(n) => add_months(dt, n, &anchor),
+        TimeStep::Year(n) => add_years(dt, n, &anchor),
+    }
+}
+
+/// Move `dt` forward (or backward, for negative `days`) by whole calendar
+/// days on its own local date, preserving time-of-day. Used for `Day`/`Week`
+/// steps so they track the calendar instead of a fixed 24h `Duration`,
+/// which would drift across a DST transition (e.g. a "daily at 09:00
+/// local" walk jumping to 10:00 the day the clocks spring forward).
+fn add_calendar_days<Tz: TimeZone>(dt: DateTime<Tz>, days: i64) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    let local = dt.naive_local();
+    let target_date = local.date() + Duration::days(days);
+    resolve_local(dt.timezone(), target_date.and_time(local.time()))
+}
+
+/// Step `dt` by `n` calendar months, clamping the day of month against
+/// `anchor`'s original day (not `dt`'s, which may already be clamped from
+/// an earlier step) so the clamp never compounds.
+fn add_months<Tz: TimeZone>(dt: DateTime<Tz>, n: i64, anchor: &DateTime<Tz>) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    let total_months = dt.year() as i64 * 12 + (dt.month() as i64 - 1) + n;
+    let year = total_months.div_euclid(12) as i32;
+    let month = (total_months.rem_euclid(12) + 1) as u32;
+    let day = anchor.day().min(days_in_month(year, month));
+
+    let naive = NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_hms_nano_opt(
+            anchor.hour(),
+            anchor.minute(),
+            anchor.second(),
+            anchor.nanosecond(),
+        )
+        .unwrap();
+    resolve_local(dt.timezone(), naive)
+}
+
+/// Step `dt` by `n` calendar years, clamping `Feb 29 -> Feb 28` against
+/// `anchor`'s original day (not `dt`'s) so a leap-day anchor returns to
+/// `Feb 29` in the next leap year instead of staying pinned to `Feb 28`
+/// forever once a non-leap year clamps it once.
+fn add_years<Tz: TimeZone>(dt: DateTime<Tz>, n: i64, anchor: &DateTime<Tz>) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    let year = dt.year() + n as i32;
+    let day = if anchor.month() == 2 && anchor.day() == 29 && !is_leap_year(year) {
+        28
+    } else {
+        anchor.day()
+    };
+
+    let naive = NaiveDate::from_ymd_opt(year, anchor.month(), day)
+        .unwrap()
+        .and_hms_nano_opt(
+            anchor.hour(),
+            anchor.minute(),
+            anchor.second(),
+            anchor.nanosecond(),
+        )
+        .unwrap();
+    resolve_local(dt.timezone(), naive)
+}
+
+/// Advance `dt` by one step, preferring `schedule` (if set) over the plain
+/// `step`. A cron schedule is matched against the instant's UTC
+/// representation and the result is converted back to `dt`'s own zone, so
+/// this works for any `Tz` even though `CronSchedule` itself only deals in
+/// `DateTime<Utc>`.
+fn advance_candidate<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    step: TimeStep,
+    schedule: &Option<CronSchedule>,
+    anchor: DateTime<Tz>,
+) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    match schedule {
+        Some(schedule) => {
+            let from = dt.with_timezone(&Utc) + Duration::seconds(1);
+            schedule.next_match(from).with_timezone(&dt.timezone())
+        }
+        None => add_step(dt, step, anchor),
+    }
+}
+
+/// Snap `dt` forward (if necessary) to the nearest instant `schedule`
+/// actually matches, leaving it untouched if there's no schedule or it
+/// already matches. Used to align the very first candidate of a scheduled
+/// range, since unlike a fixed `step` the cursor isn't guaranteed to start
+/// on a match.
+fn snap_to_schedule<Tz: TimeZone>(dt: DateTime<Tz>, schedule: &Option<CronSchedule>) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    match schedule {
+        Some(schedule) => schedule
+            .next_match(dt.with_timezone(&Utc))
+            .with_timezone(&dt.timezone()),
+        None => dt,
+    }
+}
+
+/// The inverse of `add_step`: move `dt` back by one `step`.
+fn sub_step<Tz: TimeZone>(dt: DateTime<Tz>, step: TimeStep, anchor: DateTime<Tz>) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    match step {
+        TimeStep::Second(s) => dt - Duration::seconds(s),
+        TimeStep::Minute(m) => dt - Duration::minutes(m),
+        TimeStep::Hour(h) => dt - Duration::hours(h),
+        TimeStep::Day(d) => add_calendar_days(dt, -d),
+        TimeStep::Week(w) => add_calendar_days(dt, -w * 7),
+        TimeStep::Month(n) => add_months(dt, -n, &anchor),
+        TimeStep::Year(n) => add_years(dt, -n, &anchor),
+    }
+}
+
+/// Resolve a naive local date/time back into `tz`, picking the earlier
+/// instant for an ambiguous (DST fall-back) time and falling back to
+/// treating the naive value as UTC for a non-existent (DST spring-forward)
+/// time.
+fn resolve_local<Tz: TimeZone>(tz: Tz, naive: NaiveDateTime) -> DateTime<Tz> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => dt,
+        LocalResult::Ambiguous(earliest, _latest) => earliest,
+        LocalResult::None => tz.from_utc_datetime(&naive),
+    }
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => unreachable!("month is always 1..=12"),
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn is_weekend<Tz: TimeZone>(dt: &DateTime<Tz>) -> bool {
+    matches!(dt.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// Jump `dt` directly to the start of the next applicable daily window,
+/// rather than stepping forward one `step` at a time. If `dt`'s time-of-day
+/// is before the window start, the jump lands on the same day; otherwise it
+/// lands on the following day.
+fn jump_to_window_start<Tz: TimeZone>(dt: DateTime<Tz>, window_start: NaiveTime) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    let local = dt.naive_local();
+    let target_date = if local.time() < window_start {
+        local.date()
+    } else {
+        local.date() + Duration::days(1)
+    };
+    resolve_local(dt.timezone(), target_date.and_time(window_start))
+}
+
+/// Advance `dt` to the same time-of-day on the next calendar day. The
+/// caller re-checks the allowed-weekday set afterwards, so this just
+/// advances by a whole day rather than by `step`.
+fn jump_to_next_day<Tz: TimeZone>(dt: DateTime<Tz>) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    let local = dt.naive_local();
+    let next_date = local.date() + Duration::days(1);
+    resolve_local(dt.timezone(), next_date.and_time(local.time()))
+}
+
+/// Apply the weekend, daily-window and allowed-weekday restrictions to
+/// `candidate`, jumping directly to the next valid slot (rather than
+/// re-walking `step`) until it satisfies all three, or `end` is reached.
+/// Every jump lands back at the top of the loop so an earlier check (e.g.
+/// weekend) is re-tested after a later one (e.g. window) moves the
+/// candidate to a new day.
+fn restrict_candidate<Tz: TimeZone>(
+    mut candidate: DateTime<Tz>,
+    end: &DateTime<Tz>,
+    skip_weekends: bool,
+    allowed_weekdays: &Option<[bool; 7]>,
+    daily_window: &Option<(NaiveTime, NaiveTime)>,
+) -> Option<DateTime<Tz>>
+where
+    Tz::Offset: Copy,
+{
+    loop {
+        if candidate >= *end {
+            return None;
+        }
+
+        if skip_weekends && is_weekend(&candidate) {
+            candidate = jump_to_next_day(candidate);
+            continue;
+        }
+
+        if let Some((window_start, window_end)) = daily_window {
+            let t = candidate.naive_local().time();
+            if t < *window_start || t > *window_end {
+                candidate = jump_to_window_start(candidate, *window_start);
+                continue;
+            }
+        }
+
+        if let Some(allowed) = allowed_weekdays {
+            if !allowed[candidate.weekday().num_days_from_monday() as usize] {
+                candidate = jump_to_next_day(candidate);
+                continue;
+            }
+        }
+
+        return Some(candidate);
+    }
+}
+
+/// Jump `dt` directly to the end of the previous applicable daily window,
+/// mirroring `jump_to_window_start` for backward iteration. If `dt`'s
+/// time-of-day is after the window end, the jump lands on the same day;
+/// otherwise it lands on the previous day.
+fn jump_to_window_end<Tz: TimeZone>(dt: DateTime<Tz>, window_end: NaiveTime) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    let local = dt.naive_local();
+    let target_date = if local.time() > window_end {
+        local.date()
+    } else {
+        local.date() - Duration::days(1)
+    };
+    resolve_local(dt.timezone(), target_date.and_time(window_end))
+}
+
+/// Move `dt` back to the same time-of-day on the previous calendar day,
+/// mirroring `jump_to_next_day` for backward iteration.
+fn jump_to_prev_day<Tz: TimeZone>(dt: DateTime<Tz>) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    let local = dt.naive_local();
+    let prev_date = local.date() - Duration::days(1);
+    resolve_local(dt.timezone(), prev_date.and_time(local.time()))
+}
+
+/// The backward counterpart of `restrict_candidate`: jump `candidate` down
+/// to the next valid slot at or before it until it satisfies the weekend,
+/// daily window and allowed-weekday restrictions, or `start` is passed.
+fn restrict_candidate_backward<Tz: TimeZone>(
+    mut candidate: DateTime<Tz>,
+    start: &DateTime<Tz>,
+    skip_weekends: bool,
+    allowed_weekdays: &Option<[bool; 7]>,
+    daily_window: &Option<(NaiveTime, NaiveTime)>,
+) -> Option<DateTime<Tz>>
+where
+    Tz::Offset: Copy,
+{
+    loop {
+        if candidate < *start {
+            return None;
+        }
+
+        if skip_weekends && is_weekend(&candidate) {
+            candidate = jump_to_prev_day(candidate);
+            continue;
+        }
+
+        if let Some((window_start, window_end)) = daily_window {
+            let t = candidate.naive_local().time();
+            if t < *window_start || t > *window_end {
+                candidate = jump_to_window_end(candidate, *window_end);
+                continue;
+            }
+        }
+
+        if let Some(allowed) = allowed_weekdays {
+            if !allowed[candidate.weekday().num_days_from_monday() as usize] {
+                candidate = jump_to_prev_day(candidate);
+                continue;
+            }
+        }
+
+        return Some(candidate);
+    }
+}
+
+/// The backward counterpart of `advance_candidate`: move `dt` to the
+/// previous step (or previous schedule match).
+fn retreat_candidate<Tz: TimeZone>(
+    dt: DateTime<Tz>,
+    step: TimeStep,
+    schedule: &Option<CronSchedule>,
+    anchor: DateTime<Tz>,
+) -> DateTime<Tz>
+where
+    Tz::Offset: Copy,
+{
+    match schedule {
+        Some(schedule) => {
+            let from = dt.with_timezone(&Utc) - Duration::seconds(1);
+            schedule.prev_match(from).with_timezone(&dt.timezone())
         }
+        None => sub_step(dt, step, anchor),
     }
 }
 
+/// The last step-aligned instant strictly before `end`, found by walking
+/// forward from `start` (closed-form for fixed-duration (second/minute/hour)
+/// steps, a linear scan for `Day`/`Week`/`Month`/`Year` steps, which use
+/// calendar arithmetic rather than a fixed physical duration), or the last
+/// schedule match strictly before `end` when a `CronSchedule` is in play.
+/// Returns `None` if no such instant exists at or after `start`. This seeds
+/// the back cursor used by `DoubleEndedIterator::next_back`.
+fn last_aligned_before<Tz: TimeZone>(
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    step: TimeStep,
+    schedule: &Option<CronSchedule>,
+) -> Option<DateTime<Tz>>
+where
+    Tz::Offset: Copy,
+{
+    if let Some(schedule) = schedule {
+        let probe = (end - Duration::seconds(1)).with_timezone(&Utc);
+        let matched = schedule.prev_match(probe).with_timezone(&start.timezone());
+        return if matched >= start && matched < end {
+            Some(matched)
+        } else {
+            None
+        };
+    }
+
+    if matches!(
+        step,
+        TimeStep::Second(_) | TimeStep::Minute(_) | TimeStep::Hour(_)
+    ) {
+        let step_secs = step.as_total_seconds();
+        if step_secs > 0 {
+            let total = end.signed_duration_since(start).num_seconds();
+            let k = (total - 1).div_euclid(step_secs);
+            return Some(start + Duration::seconds(k * step_secs));
+        }
+    }
+
+    let mut last = start;
+    loop {
+        let next = add_step(last, step, start);
+        if next >= end {
+            return Some(last);
+        }
+        last = next;
+    }
+}
+
+/// Find the next instant (searching backward from the back cursor) that
+/// passes the weekend, window, weekday and filter restrictions, or `None`
+/// once `start` is passed. The mirror image of `next_candidate`.
+#[allow(clippy::too_many_arguments)]
+fn prev_candidate<Tz: TimeZone>(
+    back: &mut Option<DateTime<Tz>>,
+    start: &DateTime<Tz>,
+    step: TimeStep,
+    schedule: &Option<CronSchedule>,
+    skip_weekends: bool,
+    allowed_weekdays: &Option<[bool; 7]>,
+    daily_window: &Option<(NaiveTime, NaiveTime)>,
+    filter: &Option<Box<dyn Fn(DateTime<Tz>) -> bool + Send + Sync>>,
+    anchor: DateTime<Tz>,
+) -> Option<DateTime<Tz>>
+where
+    Tz::Offset: Copy,
+{
+    loop {
+        let candidate = (*back)?;
+        if candidate < *start {
+            *back = None;
+            return None;
+        }
+
+        let candidate = match restrict_candidate_backward(
+            candidate,
+            start,
+            skip_weekends,
+            allowed_weekdays,
+            daily_window,
+        ) {
+            Some(c) => c,
+            None => {
+                *back = None;
+                return None;
+            }
+        };
+
+        *back = Some(retreat_candidate(candidate, step, schedule, anchor));
+
+        if let Some(ref f) = filter {
+            if !f(candidate) {
+                continue;
+            }
+        }
+
+        return Some(candidate);
+    }
+}
+
+/// Find the next instant that passes the weekend, window, weekday and
+/// filter restrictions starting at (and advancing) `current`, or `None` if
+/// `end` is reached. Shared by `TimeRangeIter`, `total_steps` and
+/// `total_duration_in_seconds` so the stepping/filtering rules only live in
+/// one place.
+#[allow(clippy::too_many_arguments)]
+fn next_candidate<Tz: TimeZone>(
+    current: &mut DateTime<Tz>,
+    end: &DateTime<Tz>,
+    step: TimeStep,
+    schedule: &Option<CronSchedule>,
+    skip_weekends: bool,
+    allowed_weekdays: &Option<[bool; 7]>,
+    daily_window: &Option<(NaiveTime, NaiveTime)>,
+    filter: &Option<Box<dyn Fn(DateTime<Tz>) -> bool + Send + Sync>>,
+    anchor: DateTime<Tz>,
+) -> Option<DateTime<Tz>>
+where
+    Tz::Offset: Copy,
+{
+    loop {
+        if *current >= *end {
+            return None;
+        }
+
+        let candidate = snap_to_schedule(*current, schedule);
+
+        let candidate = match restrict_candidate(
+            candidate,
+            end,
+            skip_weekends,
+            allowed_weekdays,
+            daily_window,
+        ) {
+            Some(c) => c,
+            None => {
+                *current = *end;
+                return None;
+            }
+        };
+
+        *current = advance_candidate(candidate, step, schedule, anchor);
+
+        if candidate >= *end {
+            return None;
+        }
+
+        if let Some(ref f) = filter {
+            if !f(candidate) {
+                continue;
+            }
+        }
+
+        return Some(candidate);
+    }
+}
+
+/// The `n`th instant (1-indexed) that passes the weekend, window, weekday
+/// and filter restrictions, walking forward from `start`. Returns `None` if
+/// fewer than `n` such instants exist before `end`. Used to seed the back
+/// cursor for a `count`-capped range, so reverse iteration stays within the
+/// same "first `n` instants from `start`" window as forward iteration,
+/// instead of walking back from `end` as if uncapped.
+#[allow(clippy::too_many_arguments)]
+fn nth_match_from_start<Tz: TimeZone>(
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+    step: TimeStep,
+    schedule: &Option<CronSchedule>,
+    skip_weekends: bool,
+    allowed_weekdays: &Option<[bool; 7]>,
+    daily_window: &Option<(NaiveTime, NaiveTime)>,
+    filter: &Option<Box<dyn Fn(DateTime<Tz>) -> bool + Send + Sync>>,
+    n: usize,
+) -> Option<DateTime<Tz>>
+where
+    Tz::Offset: Copy,
+{
+    let mut current = start;
+    let mut last = None;
+    for _ in 0..n {
+        last = Some(next_candidate(
+            &mut current,
+            &end,
+            step,
+            schedule,
+            skip_weekends,
+            allowed_weekdays,
+            daily_window,
+            filter,
+            start,
+        )?);
+    }
+    last
+}
+
 /// Range of time to iterate over.
-pub struct TimeRange {
+///
+/// Generic over the time zone so zone-correct (e.g. DST-aware) iteration is
+/// possible by passing `chrono_tz::Tz` or `Local`; defaults to `Utc` so
+/// existing call sites keep working unchanged.
+pub struct TimeRange<Tz: TimeZone = Utc> {
     /// Start time
-    pub start: DateTime<Utc>,
+    pub start: DateTime<Tz>,
     /// End time
-    pub end: DateTime<Utc>,
+    pub end: DateTime<Tz>,
     /// Step to increment by each iteration
     pub step: TimeStep,
-    /// Whether to skip weekends (Saturday and Sunday)
+    /// When set, replaces the uniform `step` with a cron schedule: each
+    /// candidate is the schedule's next match after the previous one,
+    /// instead of a fixed interval away.
+    pub schedule: Option<CronSchedule>,
+    /// Whether to skip weekends (Saturday and Sunday), evaluated in this
+    /// range's own time zone.
     pub skip_weekends: bool,
     /// Optional  filter function to skip certain times.
-    pub filter: Option<Box<dyn Fn(DateTime<Utc>) -> bool + Send + Sync>>,
+    pub filter: Option<Box<dyn Fn(DateTime<Tz>) -> bool + Send + Sync>>,
+    /// Optional cap on the number of instants to yield.
+    pub count: Option<usize>,
+    /// Optional daily time-of-day window (inclusive start and end) that
+    /// candidates must fall within.
+    pub daily_window: Option<(NaiveTime, NaiveTime)>,
+    /// Optional set of weekdays candidates are allowed to fall on.
+    pub allowed_weekdays: Option<[bool; 7]>,
 }
 
-pub struct TimeRangeIter {
-    current: DateTime<Utc>,
-    end: DateTime<Utc>,
-    step: Duration,
+pub struct TimeRangeIter<Tz: TimeZone = Utc> {
+    /// The range's original start, kept around (and never mutated) as the
+    /// anchor for `Month`/`Year` day-of-month clamping, so repeated steps
+    /// clamp against the original day rather than compounding drift off
+    /// whatever `current`/`back` have already clamped down to.
+    start: DateTime<Tz>,
+    current: DateTime<Tz>,
+    end: DateTime<Tz>,
+    step: TimeStep,
+    schedule: Option<CronSchedule>,
     skip_weekends: bool,
-    filter: Option<Box<dyn Fn(DateTime<Utc>) -> bool + Send + Sync>>,
+    filter: Option<Box<dyn Fn(DateTime<Tz>) -> bool + Send + Sync>>,
+    count: Option<usize>,
+    yielded: usize,
+    daily_window: Option<(NaiveTime, NaiveTime)>,
+    allowed_weekdays: Option<[bool; 7]>,
+    /// The next candidate to consider when walking backward from `end`, or
+    /// `None` once reverse iteration is exhausted. Seeded by
+    /// `last_aligned_before` so `.rev()` can walk down to `start` without
+    /// materializing the forward sequence first.
+    back: Option<DateTime<Tz>>,
+    /// Set once the front and back cursors have met or crossed, so neither
+    /// `next` nor `next_back` yields the same instant twice.
+    exhausted: bool,
 }
 
-impl Iterator for TimeRangeIter {
-    type Item = DateTime<Utc>;
+impl<Tz: TimeZone> Iterator for TimeRangeIter<Tz>
+where
+    Tz::Offset: Copy,
+{
+    type Item = DateTime<Tz>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if self.current >= self.end {
+        if self.exhausted {
+            return None;
+        }
+        if let Some(cap) = self.count {
+            if self.yielded >= cap {
                 return None;
             }
+        }
 
-            let candidate = self.current;
-            self.current = self.current + self.step;
+        let item = next_candidate(
+            &mut self.current,
+            &self.end,
+            self.step,
+            &self.schedule,
+            self.skip_weekends,
+            &self.allowed_weekdays,
+            &self.daily_window,
+            &self.filter,
+            self.start,
+        )?;
 
-            if self.skip_weekends {
-                let mut day_candidate = candidate;
-                while (day_candidate.weekday().number_from_monday() == 6
-                    || day_candidate.weekday().number_from_monday() == 7)
-                    && day_candidate < self.end
-                {
-                    day_candidate = day_candidate + self.step;
-                }
-                if day_candidate != candidate {
-                    self.current = day_candidate + self.step;
-                    if day_candidate < self.end {
-                        if let Some(ref f) = self.filter {
-                            if f(day_candidate) {
-                                return Some(day_candidate);
-                            } else {
-                                continue;
-                            }
-                        } else {
-                            return Some(day_candidate);
-                        }
-                    } else {
-                        return None;
-                    }
-                } else {
-                    if let Some(ref f) = self.filter {
-                        if f(candidate) {
-                            return Some(candidate);
-                        } else {
-                            continue;
-                        }
-                    } else {
-                        return Some(candidate);
-                    }
-                }
-            } else {
-                if let Some(ref f) = self.filter {
-                    if f(candidate) {
-                        return Some(candidate);
-                    } else {
-                        continue;
-                    }
-                } else {
-                    return Some(candidate);
-                }
+        if let Some(back) = self.back {
+            if item >= back {
+                self.exhausted = true;
+            }
+            if item > back {
+                return None;
             }
         }
+
+        self.yielded += 1;
+        Some(item)
+    }
+}
+
+impl<Tz: TimeZone> DoubleEndedIterator for TimeRangeIter<Tz>
+where
+    Tz::Offset: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+        if let Some(cap) = self.count {
+            if self.yielded >= cap {
+                return None;
+            }
+        }
+
+        let front = self.current;
+        let item = prev_candidate(
+            &mut self.back,
+            &front,
+            self.step,
+            &self.schedule,
+            self.skip_weekends,
+            &self.allowed_weekdays,
+            &self.daily_window,
+            &self.filter,
+            self.start,
+        )?;
+
+        if item <= front {
+            self.exhausted = true;
+        }
+
+        self.yielded += 1;
+        Some(item)
+    }
+}
+
+impl<Tz: TimeZone> TimeRangeIter<Tz>
+where
+    Tz::Offset: Copy,
+{
+    /// Advance the cursor by one step without yielding an instant. Does not
+    /// count against the `count()` cap.
+    pub fn skip(&mut self) {
+        self.current = advance_candidate(self.current, self.step, &self.schedule, self.start);
+    }
+
+    /// Move the cursor back by one step, so a previously-yielded (or
+    /// skipped-past) instant can be replayed.
+    pub fn rollback(&mut self) {
+        self.current = retreat_candidate(self.current, self.step, &self.schedule, self.start);
     }
 }
 
-impl IntoIterator for TimeRange {
-    type Item = DateTime<Utc>;
-    type IntoIter = TimeRangeIter;
+impl<Tz: TimeZone> IntoIterator for TimeRange<Tz>
+where
+    Tz::Offset: Copy,
+{
+    type Item = DateTime<Tz>;
+    type IntoIter = TimeRangeIter<Tz>;
 
     fn into_iter(self) -> Self::IntoIter {
+        // When `count` caps the range, reverse iteration must stay within
+        // the same "first n instants from start" window as forward
+        // iteration, not just walk back from `end` independent of the cap
+        // (see `nth_match_from_start`). Only fall back to the uncapped
+        // `end`-relative bound if fewer than `count` instants exist at all.
+        let back = match self.count {
+            Some(cap) => nth_match_from_start(
+                self.start,
+                self.end,
+                self.step,
+                &self.schedule,
+                self.skip_weekends,
+                &self.allowed_weekdays,
+                &self.daily_window,
+                &self.filter,
+                cap,
+            )
+            .or_else(|| last_aligned_before(self.start, self.end, self.step, &self.schedule)),
+            None => last_aligned_before(self.start, self.end, self.step, &self.schedule),
+        };
         TimeRangeIter {
+            start: self.start,
             current: self.start,
             end: self.end,
-            step: self.step.into(),
+            step: self.step,
+            schedule: self.schedule,
             skip_weekends: self.skip_weekends,
             filter: self.filter,
+            count: self.count,
+            yielded: 0,
+            daily_window: self.daily_window,
+            allowed_weekdays: self.allowed_weekdays,
+            back,
+            exhausted: back.is_none(),
         }
     }
 }
 
 /// A builder to create `TimeRange`.
-pub struct TimeRangeBuilder {
-    start: Option<DateTime<Utc>>,
-    end: Option<DateTime<Utc>>,
+pub struct TimeRangeBuilder<Tz: TimeZone = Utc> {
+    start: Option<DateTime<Tz>>,
+    end: Option<DateTime<Tz>>,
     step: Option<TimeStep>,
+    schedule: Option<CronSchedule>,
     skip_weekends: bool,
-    filter: Option<Box<dyn Fn(DateTime<Utc>) -> bool + Send + Sync>>,
+    filter: Option<Box<dyn Fn(DateTime<Tz>) -> bool + Send + Sync>>,
+    count: Option<usize>,
+    daily_window: Option<(NaiveTime, NaiveTime)>,
+    allowed_weekdays: Option<[bool; 7]>,
 }
 
-impl TimeRangeBuilder {
+impl<Tz: TimeZone> TimeRangeBuilder<Tz> {
     /// Create a new builder.
     pub fn new() -> Self {
         Self {
             start: None,
             end: None,
             step: None,
+            schedule: None,
             skip_weekends: false,
             filter: None,
+            count: None,
+            daily_window: None,
+            allowed_weekdays: None,
         }
     }
 
     /// Set the start time.
-    pub fn start(mut self, start: DateTime<Utc>) -> Self {
+    pub fn start(mut self, start: DateTime<Tz>) -> Self {
         self.start = Some(start);
         self
     }
 
     /// Set the end time.
-    pub fn end(mut self, end: DateTime<Utc>) -> Self {
+    pub fn end(mut self, end: DateTime<Tz>) -> Self {
         self.end = Some(end);
         self
     }
@@ -178,6 +848,15 @@ impl TimeRangeBuilder {
         self
     }
 
+    /// Replace the uniform `step` with a cron schedule: instead of a fixed
+    /// interval, each candidate is `schedule`'s next match after the
+    /// previous one. Composes with `end`, `filter` and `skip_weekends` as
+    /// usual. A `step` set separately is ignored once a schedule is set.
+    pub fn schedule(mut self, schedule: CronSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
     /// Whether to skip weekends.
     pub fn skip_weekends(mut self, skip: bool) -> Self {
         self.skip_weekends = skip;
@@ -188,17 +867,61 @@ impl TimeRangeBuilder {
     /// For example, you can skip holidays or specific conditions.
     pub fn filter<F>(mut self, f: F) -> Self
     where
-        F: Fn(DateTime<Utc>) -> bool + Send + Sync + 'static,
+        F: Fn(DateTime<Tz>) -> bool + Send + Sync + 'static,
     {
         self.filter = Some(Box::new(f));
         self
     }
 
+    /// Cap the number of instants the range will yield, counting only those
+    /// that pass the weekend-skip and filter checks.
+    pub fn count(mut self, n: usize) -> Self {
+        self.count = Some(n);
+        self
+    }
+
+    /// Restrict candidates to a daily time-of-day window (inclusive of both
+    /// `start` and `end`). When a candidate falls outside the window, the
+    /// iterator jumps directly to the next applicable window start instead
+    /// of stepping forward one `step` at a time.
+    pub fn within_daily_window(mut self, start: NaiveTime, end: NaiveTime) -> Self {
+        self.daily_window = Some((start, end));
+        self
+    }
+
+    /// Restrict candidates to the given weekdays, advancing by whole days
+    /// (rather than by `step`) past any disallowed day.
+    pub fn only_weekdays(mut self, weekdays: &[Weekday]) -> Self {
+        let mut allowed = [false; 7];
+        for weekday in weekdays {
+            allowed[weekday.num_days_from_monday() as usize] = true;
+        }
+        self.allowed_weekdays = Some(allowed);
+        self
+    }
+
     /// Build the `TimeRange`.
-    pub fn build(self) -> Result<TimeRange, &'static str> {
+    pub fn build(self) -> Result<TimeRange<Tz>, &'static str> {
         let start = self.start.ok_or("start time is required")?;
         let end = self.end.ok_or("end time is required")?;
-        let step = self.step.ok_or("step is required")?;
+        let step = match (self.step, &self.schedule) {
+            (Some(step), _) => step,
+            (None, Some(_)) => TimeStep::Second(1),
+            (None, None) => return Err("step is required"),
+        };
+
+        let amount = match step {
+            TimeStep::Second(n)
+            | TimeStep::Minute(n)
+            | TimeStep::Hour(n)
+            | TimeStep::Day(n)
+            | TimeStep::Week(n)
+            | TimeStep::Month(n)
+            | TimeStep::Year(n) => n,
+        };
+        if amount <= 0 {
+            return Err("step amount must be positive");
+        }
 
         if end <= start {
             return Err("end time must be after start time");
@@ -208,12 +931,22 @@ impl TimeRangeBuilder {
             start,
             end,
             step,
+            schedule: self.schedule,
             skip_weekends: self.skip_weekends,
             filter: self.filter,
+            count: self.count,
+            daily_window: self.daily_window,
+            allowed_weekdays: self.allowed_weekdays,
         })
     }
 }
 
+impl<Tz: TimeZone> Default for TimeRangeBuilder<Tz> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub trait ComputeTimeRange {
     /// Compute the total number of steps in the range, after applying weekend skipping and filter.
     fn total_steps(&self) -> usize;
@@ -222,44 +955,385 @@ pub trait ComputeTimeRange {
     fn total_duration_in_seconds(&self) -> i64;
 }
 
-impl ComputeTimeRange for TimeRange {
+impl<Tz: TimeZone> ComputeTimeRange for TimeRange<Tz>
+where
+    Tz::Offset: Copy,
+{
     fn total_steps(&self) -> usize {
+        if self.count.is_none() {
+            if let Some(fast) = self.fast_window_step_count() {
+                return fast;
+            }
+        }
+
         let mut count = 0usize;
         let mut current = self.start;
-        let step_duration = Duration::from(self.step);
 
-        while current < self.end {
-            let mut candidate = current;
-
-            if self.skip_weekends {
-                while (candidate.weekday().number_from_monday() == 6
-                    || candidate.weekday().number_from_monday() == 7)
-                    && candidate < self.end
-                {
-                    candidate = candidate + step_duration;
+        loop {
+            if let Some(cap) = self.count {
+                if count >= cap {
+                    break;
                 }
             }
 
-            if candidate >= self.end {
-                break;
+            match next_candidate(
+                &mut current,
+                &self.end,
+                self.step,
+                &self.schedule,
+                self.skip_weekends,
+                &self.allowed_weekdays,
+                &self.daily_window,
+                &self.filter,
+                self.start,
+            ) {
+                Some(_) => count += 1,
+                None => break,
             }
+        }
 
-            if let Some(ref f) = self.filter {
-                if !f(candidate) {
-                    current = candidate + step_duration;
-                    continue;
+        count
+    }
+
+    fn total_duration_in_seconds(&self) -> i64 {
+        // Months and years aren't a fixed number of seconds, so this sums the
+        // actual elapsed time between successive yielded instants instead of
+        // multiplying a step count by a nominal step duration.
+        let mut total = 0i64;
+        let mut current = self.start;
+        let mut last_yielded: Option<DateTime<Tz>> = None;
+        let mut yielded = 0usize;
+
+        loop {
+            if let Some(cap) = self.count {
+                if yielded >= cap {
+                    break;
                 }
             }
 
-            count += 1;
-            current = candidate + step_duration;
+            let candidate = match next_candidate(
+                &mut current,
+                &self.end,
+                self.step,
+                &self.schedule,
+                self.skip_weekends,
+                &self.allowed_weekdays,
+                &self.daily_window,
+                &self.filter,
+                self.start,
+            ) {
+                Some(c) => c,
+                None => break,
+            };
+
+            if let Some(prev) = last_yielded.take() {
+                total += candidate.signed_duration_since(prev).num_seconds();
+            }
+            last_yielded = Some(candidate);
+            yielded += 1;
         }
 
-        count
+        total
     }
+}
 
-    fn total_duration_in_seconds(&self) -> i64 {
-        let steps = self.total_steps();
-        steps as i64 * self.step.as_total_seconds()
+impl<Tz: TimeZone> TimeRange<Tz>
+where
+    Tz::Offset: Copy,
+{
+    /// Closed-form slot count for the common case: a daily window whose
+    /// length divides evenly by a fixed (non-calendar) step, with no
+    /// weekday restriction, weekend skip or custom filter to complicate the
+    /// count, a step short enough that the step after a day's last slot
+    /// cannot reach into the next day's window, and a range that starts and
+    /// ends exactly on a window-start boundary. Returns `None` if any of
+    /// those don't hold, so callers can fall back to a full scan.
+    fn fast_window_step_count(&self) -> Option<usize> {
+        if self.skip_weekends
+            || self.filter.is_some()
+            || self.allowed_weekdays.is_some()
+            || self.schedule.is_some()
+        {
+            return None;
+        }
+        if matches!(
+            self.step,
+            TimeStep::Day(_) | TimeStep::Week(_) | TimeStep::Month(_) | TimeStep::Year(_)
+        ) {
+            return None;
+        }
+        let (window_start, window_end) = self.daily_window?;
+
+        let step_secs = self.step.as_total_seconds();
+        if step_secs <= 0 {
+            return None;
+        }
+        let window_secs = (window_end - window_start).num_seconds();
+        if window_secs < 0 || window_secs % step_secs != 0 {
+            return None;
+        }
+        if window_secs + step_secs >= 86_400 {
+            return None;
+        }
+        let slots_per_day = (window_secs / step_secs + 1) as usize;
+
+        let start_local = self.start.naive_local();
+        let end_local = self.end.naive_local();
+        if start_local.time() != window_start || end_local.time() != window_start {
+            return None;
+        }
+
+        let days = (end_local.date() - start_local.date()).num_days();
+        if days < 0 {
+            return None;
+        }
+        Some(slots_per_day * days as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd_hms(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, s).unwrap()
+    }
+
+    #[test]
+    fn build_rejects_zero_step() {
+        let start = ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = ymd_hms(2024, 1, 2, 0, 0, 0);
+        let result = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Day(0))
+            .build();
+        assert_eq!(result.err(), Some("step amount must be positive"));
+    }
+
+    #[test]
+    fn build_rejects_negative_step() {
+        let start = ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = ymd_hms(2024, 1, 2, 0, 0, 0);
+        let result = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Second(-1))
+            .build();
+        assert_eq!(result.err(), Some("step amount must be positive"));
+    }
+
+    #[test]
+    fn reverse_iteration_stays_within_the_count_cap() {
+        let start = ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = ymd_hms(2024, 1, 1, 0, 0, 10);
+
+        let forward: Vec<_> = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Second(1))
+            .count(3)
+            .build()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(
+            forward,
+            vec![
+                ymd_hms(2024, 1, 1, 0, 0, 0),
+                ymd_hms(2024, 1, 1, 0, 0, 1),
+                ymd_hms(2024, 1, 1, 0, 0, 2),
+            ]
+        );
+
+        let mut reversed: Vec<_> = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Second(1))
+            .count(3)
+            .build()
+            .unwrap()
+            .into_iter()
+            .rev()
+            .collect();
+        reversed.reverse();
+        assert_eq!(forward, reversed);
+    }
+
+    #[test]
+    fn skip_then_rollback_replays_the_last_yielded_instant() {
+        let start = ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = ymd_hms(2024, 1, 1, 1, 0, 0);
+        let range = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Minute(10))
+            .build()
+            .unwrap();
+        let mut iter = range.into_iter();
+
+        assert_eq!(iter.next(), Some(ymd_hms(2024, 1, 1, 0, 0, 0)));
+        TimeRangeIter::skip(&mut iter);
+        assert_eq!(iter.next(), Some(ymd_hms(2024, 1, 1, 0, 20, 0)));
+        iter.rollback();
+        assert_eq!(iter.next(), Some(ymd_hms(2024, 1, 1, 0, 20, 0)));
+    }
+
+    #[test]
+    fn rollback_with_schedule_replays_the_schedule_match() {
+        let schedule = CronSchedule::parse("0 * * * *").unwrap();
+        let start = ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = ymd_hms(2024, 1, 1, 3, 0, 0);
+        let range = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .schedule(schedule)
+            .build()
+            .unwrap();
+        let mut iter = range.into_iter();
+
+        assert_eq!(iter.next(), Some(ymd_hms(2024, 1, 1, 0, 0, 0)));
+        iter.rollback();
+        assert_eq!(iter.next(), Some(ymd_hms(2024, 1, 1, 0, 0, 0)));
+        assert_eq!(iter.next(), Some(ymd_hms(2024, 1, 1, 1, 0, 0)));
+    }
+
+    #[test]
+    fn skip_weekends_is_rechecked_after_a_window_jump() {
+        // Friday 17:00 is the last valid slot in the window; stepping by an
+        // hour lands on Saturday 09:00 via the window jump, which must be
+        // rejected too rather than returned as a weekend instant.
+        let start = ymd_hms(2024, 1, 5, 9, 0, 0); // Friday
+        let end = ymd_hms(2024, 1, 8, 17, 1, 0); // Monday
+        let range = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Hour(1))
+            .within_daily_window(
+                NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            )
+            .skip_weekends(true)
+            .build()
+            .unwrap();
+
+        assert!(range
+            .into_iter()
+            .all(|dt| !matches!(dt.weekday(), Weekday::Sat | Weekday::Sun)));
+    }
+
+    #[test]
+    fn window_and_weekday_jumps_match_a_naive_minute_by_minute_scan() {
+        // `restrict_candidate` jumps straight to the next valid window/day
+        // instead of re-testing every intervening minute; build the same
+        // sequence by brute-force stepping one minute at a time and check
+        // the jump-based result agrees with it.
+        let start = ymd_hms(2024, 1, 3, 16, 50, 0); // Wednesday
+        let end = ymd_hms(2024, 1, 9, 9, 10, 0); // Tuesday
+        let window_start = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let window_end = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+        let allowed = [Weekday::Mon, Weekday::Wed, Weekday::Fri];
+
+        let range = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Minute(5))
+            .within_daily_window(window_start, window_end)
+            .only_weekdays(&allowed)
+            .build()
+            .unwrap();
+        let jumped: Vec<_> = range.into_iter().collect();
+
+        let mut naive = Vec::new();
+        let mut dt = start;
+        while dt < end {
+            let t = dt.naive_local().time();
+            let in_window = t >= window_start && t <= window_end;
+            let in_weekday = allowed.contains(&dt.weekday());
+            if in_window && in_weekday {
+                naive.push(dt);
+            }
+            dt += Duration::minutes(5);
+        }
+
+        assert_eq!(jumped, naive);
+        assert!(!jumped.is_empty());
+    }
+
+    #[test]
+    fn month_step_recovers_day_of_month_instead_of_compounding_the_clamp() {
+        // Jan 31 -> Feb 29 (clamped) -> Mar 31 (recovers, since the clamp is
+        // anchored off Jan 31 each time, not off the already-clamped Feb 29).
+        let start = ymd_hms(2024, 1, 31, 0, 0, 0);
+        let end = ymd_hms(2024, 4, 1, 0, 0, 0);
+        let range = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Month(1))
+            .build()
+            .unwrap();
+        let got: Vec<_> = range.into_iter().collect();
+        assert_eq!(
+            got,
+            vec![
+                ymd_hms(2024, 1, 31, 0, 0, 0),
+                ymd_hms(2024, 2, 29, 0, 0, 0),
+                ymd_hms(2024, 3, 31, 0, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn year_step_returns_to_feb_29_in_the_next_leap_year() {
+        let start = ymd_hms(2024, 2, 29, 0, 0, 0);
+        let end = ymd_hms(2029, 1, 1, 0, 0, 0);
+        let range = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Year(1))
+            .build()
+            .unwrap();
+        let got: Vec<_> = range.into_iter().collect();
+        assert_eq!(
+            got,
+            vec![
+                ymd_hms(2024, 2, 29, 0, 0, 0),
+                ymd_hms(2025, 2, 28, 0, 0, 0),
+                ymd_hms(2026, 2, 28, 0, 0, 0),
+                ymd_hms(2027, 2, 28, 0, 0, 0),
+                ymd_hms(2028, 2, 29, 0, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn daily_step_preserves_local_time_of_day_across_a_dst_spring_forward() {
+        use chrono_tz::America::New_York;
+
+        // Clocks spring forward at 2024-03-10 02:00 EST -> 03:00 EDT. A
+        // calendar-day walk at "09:00 local" must stay at 09:00 every day,
+        // not drift to 10:00 once the UTC offset changes underneath it.
+        let start = New_York.with_ymd_and_hms(2024, 3, 8, 9, 0, 0).unwrap();
+        let end = New_York.with_ymd_and_hms(2024, 3, 12, 9, 0, 0).unwrap();
+        let range = TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Day(1))
+            .build()
+            .unwrap();
+
+        assert!(range.into_iter().all(|dt| dt.hour() == 9 && dt.minute() == 0));
+    }
+
+    #[test]
+    fn build_accepts_positive_step() {
+        let start = ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = ymd_hms(2024, 1, 2, 0, 0, 0);
+        assert!(TimeRangeBuilder::new()
+            .start(start)
+            .end(end)
+            .step(TimeStep::Hour(1))
+            .build()
+            .is_ok());
     }
 }
@@ -0,0 +1,296 @@
+//! A small systemd/proxmox-calendar-event-style parser that builds a
+//! [`TimeRange`] from a single textual spec, e.g.:
+//!
+//! ```text
+//! 2024-01-01 00:00:00/2024-02-01 00:00:00 every 6 hours skip-weekends
+//! 2024-01-01 00:00:00/2024-02-01 00:00:00 daily hours=7..17/2
+//! ```
+use crate::{TimeRange, TimeRangeBuilder, TimeStep};
+use chrono::{DateTime, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+/// An error produced while parsing a calendar-event spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The overall spec is missing the `start/end` pair.
+    MissingRange,
+    /// A start or end timestamp could not be parsed.
+    InvalidDateTime(String),
+    /// The step clause (`every N unit`, `hourly`, ...) was malformed.
+    InvalidStep(String),
+    /// A `key=value` flag was not recognized.
+    UnknownFlag(String),
+    /// A repeated-range expression (`7..17/2`) was malformed.
+    InvalidRange(String),
+    /// The builder rejected the assembled range (e.g. end <= start).
+    InvalidBuilder(&'static str),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingRange => {
+                write!(f, "spec is missing a `start/end` time range")
+            }
+            ParseError::InvalidDateTime(s) => write!(f, "invalid date/time: {}", s),
+            ParseError::InvalidStep(s) => write!(f, "invalid step clause: {}", s),
+            ParseError::UnknownFlag(s) => write!(f, "unknown flag: {}", s),
+            ParseError::InvalidRange(s) => write!(f, "invalid repeated range: {}", s),
+            ParseError::InvalidBuilder(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a calendar-event style spec into a [`TimeRange`].
+///
+/// Grammar (informally):
+/// `<start>/<end> (every N unit | hourly | daily | weekly) [flags...]`
+///
+/// where `unit` is one of `second(s)`, `minute(s)`, `hour(s)`, `day(s)`, and
+/// flags are whitespace separated `skip-weekends` or `key=value` pairs. The
+/// only `key=value` flag currently understood is `hours=<range>`, where `<range>` is a
+/// systemd/proxmox-style repeated range such as `7..17/2` (every 2nd hour
+/// from 7 through 17 inclusive) or a bare `7..17` (step defaults to 1).
+pub fn parse(spec: &str) -> Result<TimeRange, ParseError> {
+    let mut tokens = spec.split_whitespace();
+
+    let first = tokens.next().ok_or(ParseError::MissingRange)?;
+    let second = tokens.next().ok_or(ParseError::MissingRange)?;
+
+    let (start_time, end_date) = second
+        .split_once('/')
+        .ok_or(ParseError::MissingRange)?;
+    let end_time = tokens.next().ok_or(ParseError::MissingRange)?;
+
+    let start = parse_datetime(first, start_time)?;
+    let end = parse_datetime(end_date, end_time)?;
+
+    let step_kind = parse_step(&mut tokens)?;
+    let mut step = step_kind.into_step();
+
+    let mut builder = TimeRangeBuilder::new().start(start).end(end);
+
+    for flag in tokens {
+        if flag == "skip-weekends" {
+            builder = builder.skip_weekends(true);
+            continue;
+        }
+
+        let (key, value) = flag
+            .split_once('=')
+            .ok_or_else(|| ParseError::UnknownFlag(flag.to_string()))?;
+
+        match key {
+            "hours" => {
+                let (hours, hour_step) = parse_repeated_range(value)?;
+                if step_kind == StepKind::Daily {
+                    // A bare `daily` step only produces one candidate per
+                    // day at `start`'s fixed time-of-day, so filtering that
+                    // single candidate by `hours=` would yield an empty
+                    // iterator almost always. Instead let the clause drive
+                    // its own hourly step through the window it names, the
+                    // same way `within_daily_window` does. The hour filter
+                    // still applies, so a start off the range's grid (e.g.
+                    // 08:00 for `7..17/2`) yields no hour outside the set.
+                    let window_start = NaiveTime::from_hms_opt(hours[0], 0, 0)
+                        .ok_or_else(|| ParseError::InvalidRange(value.to_string()))?;
+                    let window_end = NaiveTime::from_hms_opt(hours[hours.len() - 1], 0, 0)
+                        .ok_or_else(|| ParseError::InvalidRange(value.to_string()))?;
+                    step = TimeStep::Hour(hour_step as i64);
+                    builder = builder
+                        .within_daily_window(window_start, window_end)
+                        .filter(move |dt| hours.contains(&dt.hour()));
+                } else {
+                    builder = builder.filter(move |dt| hours.contains(&dt.hour()));
+                }
+            }
+            _ => return Err(ParseError::UnknownFlag(flag.to_string())),
+        }
+    }
+
+    builder
+        .step(step)
+        .build()
+        .map_err(ParseError::InvalidBuilder)
+}
+
+fn parse_datetime(date: &str, time: &str) -> Result<DateTime<Utc>, ParseError> {
+    let combined = format!("{} {}", date, time);
+    let naive = NaiveDateTime::parse_from_str(&combined, "%Y-%m-%d %H:%M:%S")
+        .map_err(|_| ParseError::InvalidDateTime(combined))?;
+    Ok(Utc.from_utc_datetime(&naive))
+}
+
+/// The step clause as parsed, distinguishing a bare `daily`/`weekly` from an
+/// explicit `every N unit` (or `hourly`) so later flags (namely `hours=`)
+/// can tell whether `step` is just the clause's default or something the
+/// user pinned down explicitly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepKind {
+    Explicit(TimeStep),
+    Daily,
+    Weekly,
+}
+
+impl StepKind {
+    fn into_step(self) -> TimeStep {
+        match self {
+            StepKind::Explicit(step) => step,
+            StepKind::Daily => TimeStep::Day(1),
+            StepKind::Weekly => TimeStep::Day(7),
+        }
+    }
+}
+
+fn parse_step<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<StepKind, ParseError> {
+    let first = tokens
+        .next()
+        .ok_or_else(|| ParseError::InvalidStep("missing step clause".to_string()))?;
+
+    match first {
+        "hourly" => Ok(StepKind::Explicit(TimeStep::Hour(1))),
+        "daily" => Ok(StepKind::Daily),
+        "weekly" => Ok(StepKind::Weekly),
+        "every" => {
+            let amount = tokens
+                .next()
+                .ok_or_else(|| ParseError::InvalidStep("missing step amount".to_string()))?;
+            let amount: i64 = amount
+                .parse()
+                .map_err(|_| ParseError::InvalidStep(format!("not a number: {}", amount)))?;
+            if amount <= 0 {
+                return Err(ParseError::InvalidStep(format!(
+                    "step amount must be positive, got {}",
+                    amount
+                )));
+            }
+            let unit = tokens
+                .next()
+                .ok_or_else(|| ParseError::InvalidStep("missing step unit".to_string()))?;
+
+            let step = match unit.trim_end_matches('s') {
+                "second" => TimeStep::Second(amount),
+                "minute" => TimeStep::Minute(amount),
+                "hour" => TimeStep::Hour(amount),
+                "day" => TimeStep::Day(amount),
+                other => return Err(ParseError::InvalidStep(format!("unknown unit: {}", other))),
+            };
+            Ok(StepKind::Explicit(step))
+        }
+        other => Err(ParseError::InvalidStep(format!(
+            "expected `every N unit`, `hourly`, `daily` or `weekly`, got `{}`",
+            other
+        ))),
+    }
+}
+
+/// Expand a systemd/proxmox-style repeated range, e.g. `7..17/2` into the
+/// inclusive set `{7, 9, 11, 13, 15, 17}` alongside the step (`2`) that
+/// produced it. A bare `7..17` defaults to step 1.
+fn parse_repeated_range(expr: &str) -> Result<(Vec<u32>, u32), ParseError> {
+    let (range, step) = match expr.split_once('/') {
+        Some((range, step)) => {
+            let step: u32 = step
+                .parse()
+                .map_err(|_| ParseError::InvalidRange(expr.to_string()))?;
+            (range, step)
+        }
+        None => (expr, 1),
+    };
+
+    if step == 0 {
+        return Err(ParseError::InvalidRange(expr.to_string()));
+    }
+
+    let (start, end) = range
+        .split_once("..")
+        .ok_or_else(|| ParseError::InvalidRange(expr.to_string()))?;
+    let start: u32 = start
+        .parse()
+        .map_err(|_| ParseError::InvalidRange(expr.to_string()))?;
+    let end: u32 = end
+        .parse()
+        .map_err(|_| ParseError::InvalidRange(expr.to_string()))?;
+
+    if start > end {
+        return Err(ParseError::InvalidRange(expr.to_string()));
+    }
+
+    Ok(((start..=end).step_by(step as usize).collect(), step))
+}
+
+impl TimeRange {
+    /// Build a [`TimeRange`] from a systemd/proxmox-calendar-event-style
+    /// spec. See the [module docs](crate::parser) for the grammar.
+    pub fn parse(spec: &str) -> Result<TimeRange, ParseError> {
+        parse(spec)
+    }
+}
+
+impl FromStr for TimeRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_zero_is_rejected() {
+        let spec = "2024-01-01 00:00:00/2024-02-01 00:00:00 every 0 minutes";
+        assert_eq!(
+            parse(spec).err(),
+            Some(ParseError::InvalidStep(
+                "step amount must be positive, got 0".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn every_negative_is_rejected() {
+        let spec = "2024-01-01 00:00:00/2024-02-01 00:00:00 every -3 hours";
+        assert_eq!(
+            parse(spec).err(),
+            Some(ParseError::InvalidStep(
+                "step amount must be positive, got -3".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn every_positive_still_parses() {
+        let spec = "2024-01-01 00:00:00/2024-02-01 00:00:00 every 6 hours";
+        assert!(parse(spec).is_ok());
+    }
+
+    #[test]
+    fn daily_hours_walks_business_hours_instead_of_yielding_nothing() {
+        let spec = "2024-01-01 00:00:00/2024-01-03 00:00:00 daily hours=7..17/2";
+        let range = parse(spec).unwrap();
+        let got: Vec<_> = range.into_iter().map(|dt| dt.to_string()).collect();
+        assert_eq!(
+            got,
+            vec![
+                "2024-01-01 07:00:00 UTC",
+                "2024-01-01 09:00:00 UTC",
+                "2024-01-01 11:00:00 UTC",
+                "2024-01-01 13:00:00 UTC",
+                "2024-01-01 15:00:00 UTC",
+                "2024-01-01 17:00:00 UTC",
+                "2024-01-02 07:00:00 UTC",
+                "2024-01-02 09:00:00 UTC",
+                "2024-01-02 11:00:00 UTC",
+                "2024-01-02 13:00:00 UTC",
+                "2024-01-02 15:00:00 UTC",
+                "2024-01-02 17:00:00 UTC",
+            ]
+        );
+    }
+}